@@ -79,6 +79,8 @@ use std::convert::TryFrom;
 
 use anyhow::{bail, Result};
 
+use super::ffi::FFIType;
+use super::literal::Literal;
 use super::record::Field;
 use super::types::Type;
 use super::{APIConverter, ComponentInterface};
@@ -88,15 +90,62 @@ use super::{APIConverter, ComponentInterface};
 ///
 /// Enums are passed across the FFI by serializing to a bytebuffer, with a
 /// i32 indicating the variant followed by the serialization of each field.
+///
+/// "Flat" enums - those whose variants carry no associated data - also have
+/// an integer representation of their discriminant, reported by
+/// [`Enum::ffi_repr`]. Function signatures do not use it: a flat enum
+/// argument or return value is still passed as a serialized bytebuffer.
 #[derive(Debug, Clone, Hash, Default)]
 pub struct Enum {
     pub(super) name: String,
     pub(super) variants: Vec<Variant>,
     // "Flat" enums do not have, and will never have, variants with associated data.
     pub(super) flat: bool,
+    // The integer type backing this enum's discriminant, e.g. as selected by
+    // a `discr_type=u8` entry beside `[Enum]` (`[Enum, discr_type=u8]`). `None` means the default
+    // representation (`i32`, matching Rust's default `repr`).
+    pub(super) discr_type: Option<Type>,
+    // Whether this is a plain data enum or an error enum backing `[Throws=...]`.
+    pub(super) kind: EnumKind,
+    // Set by `#[non_exhaustive]` or `[NonExhaustive]`: generated bindings should
+    // emit an open/`default` arm when matching, to tolerate variants added later.
+    pub(super) non_exhaustive: bool,
+    // An author-supplied override of flatness, e.g. via `#[uniffi(flat)]` /
+    // `#[uniffi(not_flat)]` or a `forced_flatness=...` WebIDL argument. Lets an
+    // `[Enum] interface` be declared flat, or a data-carrying-looking enum be
+    // forced non-flat. `None` means "use the usual heuristic".
+    pub(super) forced_flatness: Option<bool>,
     pub(super) docs: Vec<String>,
 }
 
+/// Distinguishes the handful of ways an `Enum` can be used in a `ComponentInterface`.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum EnumKind {
+    /// A plain data enum.
+    Enum,
+    /// An enum used as the error type of a `[Throws=...]` function. `flat`
+    /// matches `Enum::is_flat()`: when it is true no variant carries
+    /// associated data, and only the variant name needs to cross the FFI.
+    Error { flat: bool },
+}
+
+impl Default for EnumKind {
+    fn default() -> Self {
+        EnumKind::Enum
+    }
+}
+
+impl ComponentInterface {
+    /// Queries for error-enum definitions specifically, the counterpart of
+    /// `iter_enum_definitions` for the `[Error]` subset.
+    pub fn iter_error_definitions(&self) -> Vec<&Enum> {
+        self.iter_enum_definitions()
+            .into_iter()
+            .filter(|e| e.is_error())
+            .collect()
+    }
+}
+
 impl Enum {
     pub fn name(&self) -> &str {
         &self.name
@@ -106,8 +155,75 @@ impl Enum {
         self.variants.iter().collect()
     }
 
+    /// The number of variants in this enum, for generating a `VARIANT_COUNT`
+    /// constant in foreign bindings.
+    pub fn variant_count(&self) -> usize {
+        self.variants.len()
+    }
+
+    /// The variant names, in declaration order, for generating `is_<Variant>()`
+    /// predicates in foreign bindings.
+    pub fn variant_names(&self) -> Vec<&str> {
+        self.variants.iter().map(|v| v.name()).collect()
+    }
+
     pub fn is_flat(&self) -> bool {
-        self.flat
+        self.forced_flatness.unwrap_or(self.flat)
+    }
+
+    pub fn is_non_exhaustive(&self) -> bool {
+        self.non_exhaustive
+    }
+
+    pub fn forced_flatness(&self) -> Option<bool> {
+        self.forced_flatness
+    }
+
+    pub fn kind(&self) -> &EnumKind {
+        &self.kind
+    }
+
+    /// Is this an error enum, i.e. one that backs a `[Throws=...]` function?
+    pub fn is_error(&self) -> bool {
+        matches!(self.kind, EnumKind::Error { .. })
+    }
+
+    pub fn discr_type(&self) -> Option<&Type> {
+        self.discr_type.as_ref()
+    }
+
+    /// If this enum can be passed across the FFI as a plain integer rather
+    /// than a serialized bytebuffer, returns the `FFIType` to use for it.
+    ///
+    /// This is possible precisely when `is_flat()` is true: none of its
+    /// variants then carry associated data, so the discriminant is all
+    /// there is to transmit. An enum that is not flat gets `None` even if
+    /// none of its variants happen to have fields.
+    pub fn ffi_repr(&self) -> Option<FFIType> {
+        if !self.is_flat() {
+            return None;
+        }
+        Some(match self.discr_type {
+            Some(Type::UInt8) => FFIType::UInt8,
+            Some(Type::Int8) => FFIType::Int8,
+            Some(Type::UInt16) => FFIType::UInt16,
+            Some(Type::Int16) => FFIType::Int16,
+            Some(Type::UInt32) => FFIType::UInt32,
+            Some(Type::UInt64) => FFIType::UInt64,
+            Some(Type::Int64) => FFIType::Int64,
+            Some(Type::Int32) | None => FFIType::Int32,
+            Some(_) => unreachable!("discr_type must be an integer type"),
+        })
+    }
+
+    /// The `FFIType` this enum could be passed across the FFI as:
+    /// `ffi_repr()` when that's available, falling back to the bytebuffer
+    /// serialization every enum supports.
+    ///
+    /// Function-signature lowering does not call this method: it passes
+    /// every `Type::Enum` as `FFIType::RustBuffer`, flat or not.
+    pub fn ffi_type(&self) -> FFIType {
+        self.ffi_repr().unwrap_or(FFIType::RustBuffer)
     }
 
     pub fn contains_object_references(&self, ci: &ComponentInterface) -> bool {
@@ -129,26 +245,80 @@ impl Enum {
     }
 }
 
+/// Fill in the discriminant of each variant that didn't specify one
+/// explicitly, following Rust's own `repr` semantics: the first variant
+/// defaults to `0`, and every other implicit variant is one more than
+/// whatever came before it (explicit or otherwise).
+///
+/// Also rejects duplicate or overflowing discriminant values, which is
+/// stricter than plain Rust (which only rejects duplicates) but keeps the
+/// bindgen-side switch-on-integer logic simple.
+fn assign_discriminants(variants: &mut [Variant]) -> Result<()> {
+    // `None` once the previous value was `i64::MAX`, so that only an implicit
+    // value following it counts as an overflow.
+    let mut next: Option<i64> = Some(0);
+    let mut seen = std::collections::HashSet::new();
+    for variant in variants.iter_mut() {
+        let value = match &variant.discr {
+            Some(Literal::Int(v, _, _)) => *v,
+            Some(_) => bail!("enum discriminants must be integer literals"),
+            None => next
+                .ok_or_else(|| anyhow::anyhow!("discriminant value overflowed for enum"))?,
+        };
+        if !seen.insert(value) {
+            bail!(
+                "duplicate discriminant value {} for variant `{}`",
+                value,
+                variant.name
+            );
+        }
+        next = value.checked_add(1);
+        variant.discr = Some(Literal::Int(
+            value,
+            super::literal::Radix::Decimal,
+            Type::Int32,
+        ));
+    }
+    Ok(())
+}
+
 // Note that we have two `APIConverter` impls here - one for the `enum` case
 // and one for the `[Enum] interface` case.
 
 impl APIConverter<Enum> for weedle::EnumDefinition<'_> {
     fn convert(&self, _ci: &mut ComponentInterface) -> Result<Enum> {
+        let flat = true;
+        let mut variants = self
+            .values
+            .body
+            .list
+            .iter()
+            .map::<Result<_>, _>(|v| {
+                Ok(Variant {
+                    name: v.0.to_string(),
+                    ..Default::default()
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        assign_variant_indices(&mut variants);
+        let forced_flatness = forced_flatness_from_webidl_attributes(&self.attributes)?;
+        check_forced_flatness(forced_flatness, &variants)?;
+        // `forced_flatness` overrides the heuristic for *all* purposes - including
+        // picking `kind`'s embedded `flat` - so `Enum::is_flat()` and `Enum::kind()`
+        // never disagree about whether this enum is flat.
+        let effective_flat = forced_flatness.unwrap_or(flat);
+        // This path is always flat by default, so it normally always has discriminant
+        // values to serialize; honour an override to the contrary if one was given.
+        if effective_flat {
+            assign_discriminants(&mut variants)?;
+        }
         Ok(Enum {
             name: self.identifier.0.to_string(),
-            variants: self
-                .values
-                .body
-                .list
-                .iter()
-                .map::<Result<_>, _>(|v| {
-                    Ok(Variant {
-                        name: v.0.to_string(),
-                        ..Default::default()
-                    })
-                })
-                .collect::<Result<Vec<_>>>()?,
-            flat: true,
+            variants,
+            flat,
+            kind: error_kind_from_webidl_attributes(&self.attributes, effective_flat),
+            non_exhaustive: non_exhaustive_from_webidl_attributes(&self.attributes),
+            forced_flatness,
             ..Default::default()
         })
     }
@@ -159,46 +329,229 @@ impl APIConverter<Enum> for weedle::InterfaceDefinition<'_> {
         if self.inheritance.is_some() {
             bail!("interface inheritence is not supported for enum interfaces");
         }
-        // We don't need to check `self.attributes` here; if calling code has dispatched
-        // to this impl then we already know there was an `[Enum]` attribute.
+        // We don't need to check `self.attributes` here for the `[Enum]` marker itself;
+        // if calling code has dispatched to this impl then we already know it was present.
+        // We do still need to look for a `discr_type=...` entry beside it, which selects the
+        // integer type used when this turns out to be a flat enum (see `Enum::ffi_repr`).
+        let mut variants = self
+            .members
+            .body
+            .iter()
+            .map::<Result<Variant>, _>(|member| match member {
+                weedle::interface::InterfaceMember::Operation(t) => Ok(t.convert(ci)?),
+                _ => bail!(
+                    "interface member type {:?} not supported in enum interface",
+                    member
+                ),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        assign_variant_indices(&mut variants);
+        let forced_flatness = forced_flatness_from_webidl_attributes(&self.attributes)?;
+        check_forced_flatness(forced_flatness, &variants)?;
+        // `forced_flatness` overrides the heuristic for *all* purposes - including
+        // picking `kind`'s embedded `flat` - so `Enum::is_flat()` and `Enum::kind()`
+        // never disagree about whether this enum is flat.
+        let effective_flat = forced_flatness.unwrap_or(false);
+        // This path is normally not flat, but `forced_flatness=true` can make it so,
+        // in which case it needs discriminant values to serialize just like any
+        // other flat enum.
+        if effective_flat {
+            assign_discriminants(&mut variants)?;
+        }
         Ok(Enum {
             name: self.identifier.0.to_string(),
-            variants: self
-                .members
-                .body
-                .iter()
-                .map::<Result<Variant>, _>(|member| match member {
-                    weedle::interface::InterfaceMember::Operation(t) => Ok(t.convert(ci)?),
-                    _ => bail!(
-                        "interface member type {:?} not supported in enum interface",
-                        member
-                    ),
-                })
-                .collect::<Result<Vec<_>>>()?,
+            variants,
             flat: false,
+            discr_type: discr_type_from_webidl_attributes(&self.attributes)?,
+            kind: error_kind_from_webidl_attributes(&self.attributes, effective_flat),
+            non_exhaustive: non_exhaustive_from_webidl_attributes(&self.attributes),
+            forced_flatness,
             ..Default::default()
         })
     }
 }
 
+/// Look for a bare `[NonExhaustive]` marker in an attribute list.
+fn non_exhaustive_from_webidl_attributes(
+    attrs: &Option<weedle::attribute::ExtendedAttributeList<'_>>,
+) -> bool {
+    attrs.as_ref().map_or(false, |attrs| {
+        attrs.body.list.iter().any(|attr| {
+            matches!(attr, weedle::attribute::ExtendedAttribute::NoArgs(id) if (id.0).0 == "NonExhaustive")
+        })
+    })
+}
+
+/// Look for a `forced_flatness=true|false` argument in an attribute list,
+/// letting an author override the usual flatness heuristic - e.g. to declare
+/// an `[Enum] interface` flat, or force a plain `enum` non-flat.
+fn forced_flatness_from_webidl_attributes(
+    attrs: &Option<weedle::attribute::ExtendedAttributeList<'_>>,
+) -> Result<Option<bool>> {
+    let attrs = match attrs {
+        Some(attrs) => attrs,
+        None => return Ok(None),
+    };
+    for attr in attrs.body.list.iter() {
+        if let weedle::attribute::ExtendedAttribute::Ident(id) = attr {
+            if id.lhs_identifier.0 == "forced_flatness" {
+                return Ok(Some(match id.rhs.0 {
+                    "true" => true,
+                    "false" => false,
+                    other => bail!("forced_flatness must be `true` or `false`, got `{}`", other),
+                }));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Reject the one invalid combination: an enum forced flat despite having a
+/// variant that actually carries fields (those fields would have nowhere to
+/// go once the enum is passed across the FFI as a plain integer).
+fn check_forced_flatness(forced_flatness: Option<bool>, variants: &[Variant]) -> Result<()> {
+    if forced_flatness == Some(true) && variants.iter().any(Variant::has_fields) {
+        bail!("forced_flatness=true is invalid because at least one variant has fields");
+    }
+    Ok(())
+}
+
+/// Look for a `discr_type=<int type>` entry in an attribute list such as
+/// `[Enum, discr_type=u8]`, and resolve it to the corresponding builtin integer `Type`.
+fn discr_type_from_webidl_attributes(
+    attrs: &Option<weedle::attribute::ExtendedAttributeList<'_>>,
+) -> Result<Option<Type>> {
+    let attrs = match attrs {
+        Some(attrs) => attrs,
+        None => return Ok(None),
+    };
+    for attr in attrs.body.list.iter() {
+        if let weedle::attribute::ExtendedAttribute::Ident(id) = attr {
+            if id.lhs_identifier.0 == "discr_type" {
+                return Ok(Some(match id.rhs.0 {
+                    "u8" => Type::UInt8,
+                    "i8" => Type::Int8,
+                    "u16" => Type::UInt16,
+                    "i16" => Type::Int16,
+                    "u32" => Type::UInt32,
+                    "i32" => Type::Int32,
+                    "u64" => Type::UInt64,
+                    "i64" => Type::Int64,
+                    other => bail!("unsupported discr_type `{}`", other),
+                }));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Look for a bare `[Error]` marker in an attribute list, returning the
+/// `EnumKind` this enum should be built with. This is the WebIDL-side
+/// counterpart of `#[uniffi(Error)]` on the proc-macro path.
+fn error_kind_from_webidl_attributes(
+    attrs: &Option<weedle::attribute::ExtendedAttributeList<'_>>,
+    flat: bool,
+) -> EnumKind {
+    let is_error = attrs.as_ref().map_or(false, |attrs| {
+        attrs.body.list.iter().any(|attr| {
+            matches!(attr, weedle::attribute::ExtendedAttribute::NoArgs(id) if (id.0).0 == "Error")
+        })
+    });
+    if is_error {
+        EnumKind::Error { flat }
+    } else {
+        EnumKind::Enum
+    }
+}
+
 impl APIConverter<Enum> for &syn::ItemEnum {
     fn convert(&self, ci: &mut ComponentInterface) -> Result<Enum> {
         let attrs = super::synner::Attributes::try_from(&self.attrs)?;
-        let variants = self
+        let mut variants = self
             .variants
             .iter()
             .map(|v| v.convert(ci))
             .collect::<Result<Vec<_>>>()?;
+        assign_variant_indices(&mut variants);
         let flat = !variants.iter().any(|v| !v.fields().is_empty());
+        let forced_flatness = forced_flatness_from_attrs(&self.attrs)?;
+        check_forced_flatness(forced_flatness, &variants)?;
+        // `forced_flatness` overrides the heuristic for *all* purposes - including
+        // picking `kind`'s embedded `flat` - so `Enum::is_flat()` and `Enum::kind()`
+        // never disagree about whether this enum is flat.
+        let effective_flat = forced_flatness.unwrap_or(flat);
+        if effective_flat {
+            assign_discriminants(&mut variants)?;
+        }
+        let kind = if is_error_enum(&self.attrs)? {
+            EnumKind::Error {
+                flat: effective_flat,
+            }
+        } else {
+            EnumKind::Enum
+        };
         Ok(Enum {
             name: self.ident.to_string(),
             variants,
             flat,
+            kind,
+            non_exhaustive: is_non_exhaustive(&self.attrs),
+            forced_flatness,
             docs: attrs.docs,
+            ..Default::default()
         })
     }
 }
 
+/// Is this enum marked `#[uniffi(Error)]`, i.e. is it the error type of a
+/// `[Throws=...]` function?
+fn is_error_enum(attrs: &[syn::Attribute]) -> Result<bool> {
+    for attr in attrs {
+        if !attr.path.is_ident("uniffi") {
+            continue;
+        }
+        if let syn::Meta::List(list) = attr.parse_meta()? {
+            if list.nested.iter().any(|nested| {
+                matches!(nested, syn::NestedMeta::Meta(syn::Meta::Path(p)) if p.is_ident("Error"))
+            }) {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// Is this enum marked `#[non_exhaustive]`?
+fn is_non_exhaustive(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .any(|attr| attr.path.is_ident("non_exhaustive"))
+}
+
+/// Look for a `#[uniffi(flat)]` or `#[uniffi(not_flat)]` override, letting an
+/// author force this enum's flatness rather than relying on the "any variant
+/// has fields" heuristic.
+fn forced_flatness_from_attrs(attrs: &[syn::Attribute]) -> Result<Option<bool>> {
+    for attr in attrs {
+        if !attr.path.is_ident("uniffi") {
+            continue;
+        }
+        if let syn::Meta::List(list) = attr.parse_meta()? {
+            for nested in list.nested.iter() {
+                if let syn::NestedMeta::Meta(syn::Meta::Path(p)) = nested {
+                    if p.is_ident("flat") {
+                        return Ok(Some(true));
+                    }
+                    if p.is_ident("not_flat") {
+                        return Ok(Some(false));
+                    }
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
 /// Represents an individual variant in an Enum.
 ///
 /// Each variant has a name and zero or more fields.
@@ -206,6 +559,21 @@ impl APIConverter<Enum> for &syn::ItemEnum {
 pub struct Variant {
     pub(super) name: String,
     pub(super) fields: Vec<Field>,
+    // True if this variant's fields were declared tuple-style (`One(u32)`)
+    // rather than with names (`One { first: u32 }`). The fields themselves
+    // still get synthesized names (`v0`, `v1`, ...) so that downstream code
+    // can treat `fields` uniformly; bindgen consults this flag to decide
+    // whether to reconstruct `Self::One(x)` or `Self::One { v0: x }`.
+    pub(super) is_tuple: bool,
+    // The discriminant value used to represent this variant across the FFI
+    // when its enum is flat. Explicit values come from a Rust `= <int>`
+    // discriminant; implicit ones are filled in by `assign_discriminants`.
+    pub(super) discr: Option<Literal>,
+    // This variant's stable position among its enum's variants, assigned by
+    // `assign_variant_indices`. Unrelated to `discr`: it's always a plain
+    // `0..variant_count` sequence, so bindgen can switch on a compact integer
+    // for *any* enum, flat or not, rather than string-matching variant names.
+    pub(super) index: usize,
     pub(super) docs: Vec<String>,
 }
 
@@ -217,6 +585,20 @@ impl Variant {
         self.fields.iter().collect()
     }
 
+    pub fn is_tuple(&self) -> bool {
+        self.is_tuple
+    }
+
+    pub fn discriminant(&self) -> Option<&Literal> {
+        self.discr.as_ref()
+    }
+
+    /// This variant's stable position among its enum's variants - see
+    /// `Enum::variant_names()` for the corresponding ordered name list.
+    pub fn discriminant_index(&self) -> usize {
+        self.index
+    }
+
     pub fn docs(&self) -> Vec<&str> {
         self.docs.iter().map(String::as_str).collect()
     }
@@ -226,6 +608,19 @@ impl Variant {
     }
 }
 
+/// Synthesize the positional field name (`v0`, `v1`, ...) used for a
+/// tuple-style variant field at the given index.
+fn tuple_field_name(index: usize) -> String {
+    format!("v{}", index)
+}
+
+/// Assign each variant its stable position among its enum's variants.
+fn assign_variant_indices(variants: &mut [Variant]) {
+    for (i, variant) in variants.iter_mut().enumerate() {
+        variant.index = i;
+    }
+}
+
 impl APIConverter<Variant> for weedle::interface::OperationInterfaceMember<'_> {
     fn convert(&self, ci: &mut ComponentInterface) -> Result<Variant> {
         if self.special.is_some() {
@@ -250,15 +645,23 @@ impl APIConverter<Variant> for weedle::interface::OperationInterfaceMember<'_> {
                 _ => bail!("enum interface members must have plain identifers as names"),
             }
         };
+        let fields = self
+            .args
+            .body
+            .list
+            .iter()
+            .map(|arg| arg.convert(ci))
+            .collect::<Result<Vec<_>>>()?;
+        // Unlike a Rust tuple variant's fields, a WebIDL operation's arguments
+        // always carry an identifier (`One(u32 first);`, never `One(u32);`) -
+        // the grammar `weedle` parses has no anonymous-argument form. So an
+        // `[Enum] interface` variant authored in UDL is always named-style;
+        // tuple-style (unnamed) fields are only reachable via the proc-macro
+        // spelling below, where `syn::Fields::Unnamed` is a real distinct case.
         Ok(Variant {
             name,
-            fields: self
-                .args
-                .body
-                .list
-                .iter()
-                .map(|arg| arg.convert(ci))
-                .collect::<Result<Vec<_>>>()?,
+            fields,
+            is_tuple: false,
             ..Default::default()
         })
     }
@@ -267,26 +670,80 @@ impl APIConverter<Variant> for weedle::interface::OperationInterfaceMember<'_> {
 impl APIConverter<Variant> for &syn::Variant {
     fn convert(&self, ci: &mut ComponentInterface) -> Result<Variant> {
         let attrs = super::synner::Attributes::try_from(&self.attrs)?;
-        if self.discriminant.is_some() {
-            bail!("Explicit enum discriminants are not supported");
-        }
-        let fields = match &self.fields {
-            syn::Fields::Unit => vec![],
-            syn::Fields::Unnamed(_) => bail!("Enum variants can only have named fields"),
-            syn::Fields::Named(f) => f
-                .named
-                .iter()
-                .map(|f| f.convert(ci))
-                .collect::<Result<Vec<_>>>()?,
+        let discr = match &self.discriminant {
+            Some((_, expr)) => Some(parse_discriminant_literal(expr)?),
+            None => None,
+        };
+        let (fields, is_tuple) = match &self.fields {
+            syn::Fields::Unit => (vec![], false),
+            syn::Fields::Named(f) => (
+                f.named
+                    .iter()
+                    .map(|f| f.convert(ci))
+                    .collect::<Result<Vec<_>>>()?,
+                false,
+            ),
+            syn::Fields::Unnamed(f) => {
+                use syn::spanned::Spanned;
+                (
+                    f.unnamed
+                        .iter()
+                        .enumerate()
+                        .map(|(i, field)| {
+                            let mut named_field = field.clone();
+                            named_field.ident =
+                                Some(syn::Ident::new(&tuple_field_name(i), field.span()));
+                            (&named_field).convert(ci)
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                    true,
+                )
+            }
         };
         Ok(Variant {
             name: self.ident.to_string(),
             fields,
+            is_tuple,
+            discr,
             docs: attrs.docs,
+            ..Default::default()
         })
     }
 }
 
+/// Parse an explicit `= <expr>` enum discriminant. We only support plain
+/// integer literals (optionally negative), matching the common case of
+/// `#[repr(uN/iN)]` enums; anything fancier (const references, arithmetic)
+/// isn't something bindgen can evaluate without a full Rust compiler.
+fn parse_discriminant_literal(expr: &syn::Expr) -> Result<Literal> {
+    fn literal_value(lit: &syn::ExprLit) -> Result<i64> {
+        match &lit.lit {
+            syn::Lit::Int(i) => Ok(i.base10_parse()?),
+            _ => bail!("enum discriminants must be integer literals"),
+        }
+    }
+    let value = match expr {
+        syn::Expr::Lit(lit) => literal_value(lit)?,
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => {
+            if let syn::Expr::Lit(lit) = expr.as_ref() {
+                -literal_value(lit)?
+            } else {
+                bail!("enum discriminants must be integer literals")
+            }
+        }
+        _ => bail!("enum discriminants must be integer literals"),
+    };
+    Ok(Literal::Int(
+        value,
+        super::literal::Radix::Decimal,
+        Type::Int32,
+    ))
+}
+
 impl APIConverter<Field> for weedle::argument::Argument<'_> {
     fn convert(&self, ci: &mut ComponentInterface) -> Result<Field> {
         match self {
@@ -323,8 +780,173 @@ impl APIConverter<Field> for weedle::argument::SingleArgument<'_> {
 #[cfg(test)]
 mod test {
     use super::super::ffi::FFIType;
+    use super::super::literal::Radix;
     use super::*;
 
+    fn int_literal(value: i64) -> Literal {
+        Literal::Int(value, Radix::Decimal, Type::Int32)
+    }
+
+    fn discr_values(variants: &[Variant]) -> Vec<i64> {
+        variants
+            .iter()
+            .map(|v| match v.discriminant() {
+                Some(Literal::Int(n, _, _)) => *n,
+                other => panic!("expected an integer discriminant, got {:?}", other),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_assign_discriminants_sequential_defaults() {
+        let mut variants = vec![
+            Variant {
+                name: "A".into(),
+                ..Default::default()
+            },
+            Variant {
+                name: "B".into(),
+                discr: Some(int_literal(5)),
+                ..Default::default()
+            },
+            Variant {
+                name: "C".into(),
+                ..Default::default()
+            },
+        ];
+        assign_discriminants(&mut variants).unwrap();
+        assert_eq!(discr_values(&variants), vec![0, 5, 6]);
+    }
+
+    #[test]
+    fn test_assign_discriminants_rejects_duplicates() {
+        let mut variants = vec![
+            Variant {
+                name: "A".into(),
+                discr: Some(int_literal(1)),
+                ..Default::default()
+            },
+            Variant {
+                name: "B".into(),
+                discr: Some(int_literal(1)),
+                ..Default::default()
+            },
+        ];
+        assert!(assign_discriminants(&mut variants).is_err());
+    }
+
+    #[test]
+    fn test_assign_discriminants_rejects_overflow() {
+        let mut variants = vec![
+            Variant {
+                name: "A".into(),
+                discr: Some(int_literal(i64::MAX)),
+                ..Default::default()
+            },
+            Variant {
+                name: "B".into(),
+                ..Default::default()
+            },
+        ];
+        assert!(assign_discriminants(&mut variants).is_err());
+    }
+
+    #[test]
+    fn test_plain_webidl_enum_gets_sequential_discriminants() {
+        const UDL: &str = r#"
+            namespace test{};
+            enum TestEnum { "one", "two", "three" };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let e = ci.get_enum_definition("TestEnum").unwrap();
+        assert_eq!(
+            e.variants()
+                .iter()
+                .map(|v| match v.discriminant() {
+                    Some(Literal::Int(n, _, _)) => *n,
+                    other => panic!("expected an integer discriminant, got {:?}", other),
+                })
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_error_enum() {
+        const UDL: &str = r#"
+            namespace test{};
+            [Error]
+            enum TestError { "broken", "very_broken" };
+
+            enum TestEnum { "one", "two" };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        assert_eq!(ci.iter_enum_definitions().len(), 2);
+
+        let e = ci.get_enum_definition("TestError").unwrap();
+        assert!(e.is_error());
+        assert_eq!(e.kind(), &EnumKind::Error { flat: true });
+
+        let plain = ci.get_enum_definition("TestEnum").unwrap();
+        assert!(!plain.is_error());
+        assert_eq!(plain.kind(), &EnumKind::Enum);
+
+        let errors = ci.iter_error_definitions();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].name(), "TestError");
+    }
+
+    #[test]
+    fn test_webidl_enum_interface_variants_are_never_tuple_style() {
+        // WebIDL operation arguments always carry an identifier - `weedle`
+        // has no grammar for an anonymous `One(u32);` argument - so an
+        // `[Enum] interface` variant authored in UDL is always named-style,
+        // even a zero-argument one like `Zero()`. Real tuple-style (unnamed)
+        // fields are only reachable via the proc-macro spelling, where
+        // `syn::Fields::Unnamed` is a distinct, parseable case.
+        const UDL: &str = r##"
+            namespace test{};
+            [Enum]
+            interface TestEnumNamed {
+                Zero();
+                One(u32 first);
+                Two(u32 first, string second);
+            };
+        "##;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let e = ci.get_enum_definition("TestEnumNamed").unwrap();
+
+        assert!(e.variants().iter().all(|v| !v.is_tuple()));
+        assert_eq!(
+            e.variants()[2]
+                .fields()
+                .iter()
+                .map(|f| f.name())
+                .collect::<Vec<_>>(),
+            vec!["first", "second"]
+        );
+    }
+
+    #[test]
+    fn test_variant_introspection_helpers() {
+        const UDL: &str = r#"
+            namespace test{};
+            enum TestEnum { "one", "two", "three" };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let e = ci.get_enum_definition("TestEnum").unwrap();
+
+        assert_eq!(e.variant_count(), 3);
+        assert_eq!(e.variant_names(), vec!["one", "two", "three"]);
+        assert_eq!(
+            e.variants()
+                .iter()
+                .map(|v| v.discriminant_index())
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+    }
+
     #[test]
     fn test_duplicate_variants() {
         const UDL: &str = r#"
@@ -434,9 +1056,11 @@ mod test {
         assert_eq!(ewd.variants()[0].fields().len(), 0);
         assert_eq!(ewd.variants()[1].fields().len(), 0);
 
-        // Flat enums pass over the FFI as bytebuffers.
-        // (It might be nice to optimize these to pass as plain integers, but that's
-        // difficult atop the current factoring of `ComponentInterface` and friends).
+        // `TestEnum` is flat, so `Enum::ffi_type()` reports the narrower
+        // integer representation it could be passed as...
+        assert_eq!(e.ffi_type(), FFIType::Int32);
+        // ...but function-signature lowering does not consult it, so functions
+        // pass this enum over the FFI as a bytebuffer.
         let farg = ci.get_function_definition("takes_an_enum").unwrap();
         assert_eq!(farg.arguments()[0].type_(), Type::Enum("TestEnum".into()));
         assert_eq!(farg.ffi_func().arguments()[0].type_(), FFIType::RustBuffer);
@@ -465,4 +1089,30 @@ mod test {
             Some(FFIType::RustBuffer)
         ));
     }
+
+    #[test]
+    fn test_forced_flatness_rejects_variants_with_fields() {
+        const UDL: &str = r#"
+            namespace test{};
+            [Enum, forced_flatness=true]
+            interface TestForcedFlatnessBad {
+                One(u32 first);
+            };
+        "#;
+        assert!(ComponentInterface::from_webidl(UDL).is_err());
+    }
+
+    #[test]
+    fn test_forced_flatness_kind_agrees_with_is_flat() {
+        const UDL: &str = r#"
+            namespace test{};
+            [Error, Enum, forced_flatness=true]
+            interface TestForcedFlatError { A(); B(); };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let e = ci.get_enum_definition("TestForcedFlatError").unwrap();
+
+        assert!(e.is_flat());
+        assert_eq!(e.kind(), &EnumKind::Error { flat: true });
+    }
 }